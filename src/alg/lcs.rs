@@ -1,12 +1,33 @@
 use crate::ds::Matrix;
 
-pub fn lcs<'a, T>(sa: &'a [T], sb: &'a [T]) -> Option<&'a [T]>
-    where
-        T: Copy + PartialEq
+/// Finds the longest common subsequence of `sa` and `sb`.
+///
+/// Unlike a common *substring*, the elements of the subsequence need not be
+/// contiguous in either input, only in the same relative order.
+pub fn lcs<T>(sa: &[T], sb: &[T]) -> Vec<T>
+where
+    T: Copy + PartialEq,
 {
     let m = create_and_fill_matrix(sa, sb);
 
-    None
+    let mut result = Vec::new();
+    let mut i = sa.len();
+    let mut j = sb.len();
+
+    while i > 0 && j > 0 {
+        if sa[i - 1] == sb[j - 1] {
+            result.push(sa[i - 1]);
+            i -= 1;
+            j -= 1;
+        } else if m.get(i - 1, j) >= m.get(i, j - 1) {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+    }
+
+    result.reverse();
+    result
 }
 
 fn create_and_fill_matrix<'a, T>(sa: &'a [T], sb: &'a [T]) -> Matrix<u32>
@@ -17,16 +38,14 @@ where
 
     m.fill(0);
 
-    let mut count = 0;
-
     for i in 0..sa.len() {
         for j in 0..sb.len() {
-            if sa[i] == sb[j] {
-                count = m.get(i, j) + 1;
-            }
-            if count > 0 {
-                m.set(i + 1, j + 1, count);
-            }
+            let value = if sa[i] == sb[j] {
+                m.get(i, j) + 1
+            } else {
+                m.get(i, j + 1).max(m.get(i + 1, j))
+            };
+            m.set(i + 1, j + 1, value);
         }
     }
 
@@ -35,11 +54,32 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::alg::lcs::create_and_fill_matrix;
+    use super::{create_and_fill_matrix, lcs};
 
-    # [test]
+    #[test]
     fn create_and_fill_matrix_ok() {
         let m = create_and_fill_matrix("XXXaXXXbXXXcXX".as_ref(), "YYaYYYYbYcYYYYY".as_ref());
         println!("{:?}", m);
     }
+
+    #[test]
+    fn lcs_disjoint_ok() {
+        let result = lcs("abc".as_bytes(), "xyz".as_bytes());
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn lcs_identical_ok() {
+        let sa: Vec<char> = "abcdef".chars().collect();
+        let sb: Vec<char> = "abcdef".chars().collect();
+        assert_eq!(lcs(&sa, &sb), sa);
+    }
+
+    #[test]
+    fn lcs_ok() {
+        let sa: Vec<char> = "XXXaXXXbXXXcXX".chars().collect();
+        let sb: Vec<char> = "YYaYYYYbYcYYYYY".chars().collect();
+        let result = lcs(&sa, &sb);
+        assert_eq!(result, vec!['a', 'b', 'c']);
+    }
 }
\ No newline at end of file
@@ -1,3 +1,14 @@
+pub mod errors;
+pub mod graph;
+mod hld;
+pub mod matrix;
+mod seg_tree;
+mod union_find;
+
+pub use hld::Hld;
+pub use seg_tree::SegTree;
+pub use union_find::UnionFind;
+
 use std::fmt::{Debug, Formatter, Error};
 
 pub struct Matrix<T> {
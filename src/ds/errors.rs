@@ -0,0 +1,89 @@
+use std::fmt::{Display, Error, Formatter};
+
+//////////////////////////////////////////////////////////////////////
+#[derive(Debug, Clone)]
+pub struct NotUndirectedGraphError {
+    pub message: String,
+}
+
+impl NotUndirectedGraphError {
+    pub fn new() -> NotUndirectedGraphError {
+        NotUndirectedGraphError {
+            message: "Operation requires an undirected (non-oriented) graph.".to_string(),
+        }
+    }
+}
+
+impl Display for NotUndirectedGraphError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "{}", &self.message)
+    }
+}
+
+impl std::error::Error for NotUndirectedGraphError {}
+
+//////////////////////////////////////////////////////////////////////
+#[derive(Debug, Clone)]
+pub struct GraphParseError {
+    pub message: String,
+}
+
+impl GraphParseError {
+    pub fn new(message: &str) -> GraphParseError {
+        GraphParseError {
+            message: message.to_string(),
+        }
+    }
+}
+
+impl Display for GraphParseError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "{}", &self.message)
+    }
+}
+
+impl std::error::Error for GraphParseError {}
+
+//////////////////////////////////////////////////////////////////////
+#[derive(Debug, Clone)]
+pub struct MatrixBytesError {
+    pub message: String,
+}
+
+impl MatrixBytesError {
+    pub fn new(message: &str) -> MatrixBytesError {
+        MatrixBytesError {
+            message: message.to_string(),
+        }
+    }
+}
+
+impl Display for MatrixBytesError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "{}", &self.message)
+    }
+}
+
+impl std::error::Error for MatrixBytesError {}
+
+//////////////////////////////////////////////////////////////////////
+#[derive(Debug, Clone)]
+pub struct MatrixError {
+    pub message: String,
+}
+
+impl MatrixError {
+    pub fn new(message: &str) -> MatrixError {
+        MatrixError {
+            message: message.to_string(),
+        }
+    }
+}
+
+impl Display for MatrixError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "{}", &self.message)
+    }
+}
+
+impl std::error::Error for MatrixError {}
@@ -1,4 +1,11 @@
-use std::collections::HashMap;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::ops::Add;
+use std::str::FromStr;
+
+use crate::ds::errors::{GraphParseError, NotUndirectedGraphError};
+use crate::ds::matrix::Matrix;
+use crate::ds::UnionFind;
 
 /// Node of the graph.
 ///
@@ -131,6 +138,82 @@ where
         }
     }
 
+    /// Labels every node with its connected-component id.
+    ///
+    /// Node `id` is used as the index into the returned `Vec`; unoriented
+    /// and oriented graphs alike are treated as undirected for this query,
+    /// since components are computed purely by unioning edge endpoints.
+    pub fn connected_components(&self) -> Vec<usize> {
+        let mut uf = UnionFind::new(self.nodes.len());
+        for (&from, edges) in &self.links {
+            for edge in edges {
+                uf.union(from, edge.linked);
+            }
+        }
+        (0..self.nodes.len()).map(|id| uf.find(id)).collect()
+    }
+
+    /// Returns, for every node id, the ids of its directly linked neighbors.
+    ///
+    pub fn adjacency_list(&self) -> Vec<Vec<usize>> {
+        let n = self.nodes.len();
+        let mut adj = vec![Vec::new(); n];
+        for (&from, list) in &self.links {
+            for edge in list {
+                adj[from].push(edge.linked);
+            }
+        }
+        adj
+    }
+
+    /// Traverses the graph breadth-first from `start`, returning node ids in visitation order.
+    ///
+    pub fn bfs(&self, start: usize) -> Vec<usize> {
+        let mut visited = vec![false; self.nodes.len()];
+        let mut order = Vec::new();
+        let mut queue = VecDeque::new();
+
+        visited[start] = true;
+        queue.push_back(start);
+
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+            if let Some(list) = self.links.get(&node) {
+                for edge in list {
+                    if !visited[edge.linked] {
+                        visited[edge.linked] = true;
+                        queue.push_back(edge.linked);
+                    }
+                }
+            }
+        }
+
+        order
+    }
+
+    /// Traverses the graph depth-first from `start`, returning node ids in visitation order.
+    ///
+    pub fn dfs(&self, start: usize) -> Vec<usize> {
+        let mut visited = vec![false; self.nodes.len()];
+        let mut order = Vec::new();
+        let mut stack = vec![start];
+        visited[start] = true;
+
+        while let Some(node) = stack.pop() {
+            order.push(node);
+            if let Some(list) = self.links.get(&node) {
+                for edge in list {
+                    if !visited[edge.linked] {
+                        visited[edge.linked] = true;
+                        stack.push(edge.linked);
+                    }
+                }
+            }
+        }
+
+        order
+    }
+
     // Removes node from graph by `id`.
     //
     // This removal occurs with constant complexity O(1)~
@@ -140,6 +223,252 @@ where
     // }
 }
 
+impl<T, W> Graph<T, W>
+where
+    W: Ord + Clone,
+{
+    /// Builds a minimum spanning tree using Kruskal's algorithm.
+    ///
+    /// Returns the chosen edges as `(from, to, weight)` triples, in the
+    /// order they were accepted. Only edges with a `weight` participate;
+    /// unweighted edges are ignored. Fails with `NotUndirectedGraphError`
+    /// if the graph is oriented.
+    pub fn mst_kruskal(&self) -> Result<Vec<(usize, usize, W)>, NotUndirectedGraphError> {
+        if self.oriented {
+            return Err(NotUndirectedGraphError::new());
+        }
+
+        let mut edges: Vec<(usize, usize, W)> = Vec::new();
+        for (&from, list) in &self.links {
+            for edge in list {
+                if from < edge.linked {
+                    if let Some(weight) = &edge.weight {
+                        edges.push((from, edge.linked, weight.clone()));
+                    }
+                }
+            }
+        }
+        edges.sort_by(|a, b| a.2.cmp(&b.2));
+
+        let mut uf = UnionFind::new(self.nodes.len());
+        let mut result = Vec::new();
+        let target = self.nodes_count().saturating_sub(1);
+
+        for (from, to, weight) in edges {
+            if !uf.same(from, to) {
+                uf.union(from, to);
+                result.push((from, to, weight));
+                if result.len() == target {
+                    break;
+                }
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl<T, W> Graph<T, W>
+where
+    W: Ord + Clone + Add<Output = W> + Default,
+{
+    /// Builds a minimum spanning tree using Prim's algorithm, starting from `start`.
+    ///
+    /// Returns the chosen edges as `(from, to, weight)` triples together
+    /// with the total weight of the tree. Fails with
+    /// `NotUndirectedGraphError` if the graph is oriented.
+    pub fn mst_prim(&self, start: usize) -> Result<(Vec<(usize, usize, W)>, W), NotUndirectedGraphError> {
+        if self.oriented {
+            return Err(NotUndirectedGraphError::new());
+        }
+
+        let mut visited = vec![false; self.nodes.len()];
+        let mut heap: BinaryHeap<Reverse<(W, usize, usize)>> = BinaryHeap::new();
+        let mut result = Vec::new();
+        let mut total = W::default();
+
+        visited[start] = true;
+        if let Some(list) = self.links.get(&start) {
+            for edge in list {
+                if let Some(weight) = &edge.weight {
+                    heap.push(Reverse((weight.clone(), start, edge.linked)));
+                }
+            }
+        }
+
+        while let Some(Reverse((weight, from, to))) = heap.pop() {
+            if visited[to] {
+                continue;
+            }
+            visited[to] = true;
+            total = total + weight.clone();
+            result.push((from, to, weight));
+
+            if let Some(list) = self.links.get(&to) {
+                for edge in list {
+                    if !visited[edge.linked] {
+                        if let Some(w) = &edge.weight {
+                            heap.push(Reverse((w.clone(), to, edge.linked)));
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok((result, total))
+    }
+}
+
+impl<T, W> Graph<T, W>
+where
+    W: Ord + Clone + Add<Output = W> + Default,
+{
+    /// Finds the shortest path from `from` to `to` using Dijkstra's algorithm.
+    ///
+    /// Requires non-negative edge weights. Returns the node path together
+    /// with its total weight, or `None` if `to` is unreachable from `from`.
+    pub fn shortest_path(&self, from: usize, to: usize) -> Option<(Vec<usize>, W)> {
+        let n = self.nodes.len();
+        let mut dist: Vec<Option<W>> = vec![None; n];
+        let mut prev: Vec<Option<usize>> = vec![None; n];
+        let mut heap = BinaryHeap::new();
+
+        dist[from] = Some(W::default());
+        heap.push(Reverse((W::default(), from)));
+
+        while let Some(Reverse((cost, node))) = heap.pop() {
+            if matches!(&dist[node], Some(d) if *d < cost) {
+                continue;
+            }
+            if node == to {
+                break;
+            }
+            if let Some(list) = self.links.get(&node) {
+                for edge in list {
+                    if let Some(weight) = &edge.weight {
+                        let next_cost = cost.clone() + weight.clone();
+                        let better = match &dist[edge.linked] {
+                            Some(d) => next_cost < *d,
+                            None => true,
+                        };
+                        if better {
+                            dist[edge.linked] = Some(next_cost.clone());
+                            prev[edge.linked] = Some(node);
+                            heap.push(Reverse((next_cost, edge.linked)));
+                        }
+                    }
+                }
+            }
+        }
+
+        dist[to].clone().map(|total| {
+            let mut path = vec![to];
+            let mut current = to;
+            while let Some(p) = prev[current] {
+                path.push(p);
+                current = p;
+            }
+            path.reverse();
+            (path, total)
+        })
+    }
+}
+
+impl<T, W> Graph<T, W>
+where
+    W: FromStr + Clone + Default + PartialOrd,
+{
+    /// Builds a `Graph` from a whitespace-separated adjacency-matrix text.
+    ///
+    /// One row per line, `n` columns for `n` nodes; a non-zero entry
+    /// `(i, j)` creates an edge `i -> j` with that entry as its weight.
+    /// Fails if the matrix is not square, an entry does not parse as `W` or
+    /// is below `W::default()`, or (for an undirected `oriented == false`
+    /// graph) the matrix is not symmetric, i.e. `(i, j)` and `(j, i)` disagree.
+    /// Entries are not checked to be integers: any value `W` parses is
+    /// accepted (e.g. `1.5` for a floating-point `W`).
+    pub fn from_adjacency_matrix(text: &str, oriented: bool) -> Result<Graph<T, W>, GraphParseError> {
+        let rows: Vec<Vec<&str>> = text
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.split_whitespace().collect())
+            .collect();
+
+        let n = rows.len();
+        if n == 0 {
+            return Err(GraphParseError::new("adjacency matrix is empty"));
+        }
+        for row in &rows {
+            if row.len() != n {
+                return Err(GraphParseError::new("adjacency matrix is not square"));
+            }
+        }
+
+        let mut weights: Vec<Vec<W>> = Vec::with_capacity(n);
+        for row in &rows {
+            let mut parsed_row = Vec::with_capacity(n);
+            for cell in row {
+                let value = cell
+                    .parse::<W>()
+                    .map_err(|_| GraphParseError::new(&format!("invalid matrix entry: {}", cell)))?;
+                if value < W::default() {
+                    return Err(GraphParseError::new(&format!("negative matrix entry: {}", cell)));
+                }
+                parsed_row.push(value);
+            }
+            weights.push(parsed_row);
+        }
+
+        if !oriented {
+            for i in 0..n {
+                for j in (i + 1)..n {
+                    if weights[i][j] != weights[j][i] {
+                        return Err(GraphParseError::new(&format!(
+                            "matrix is not symmetric at ({}, {})",
+                            i, j
+                        )));
+                    }
+                }
+            }
+        }
+
+        let mut graph = Graph::new(oriented);
+        for _ in 0..n {
+            graph.add_node(None, None, None);
+        }
+        for i in 0..n {
+            let js = if oriented { 0..n } else { i..n };
+            for j in js {
+                if weights[i][j] != W::default() {
+                    graph.add_link(i, j, Some(weights[i][j].clone()));
+                }
+            }
+        }
+
+        Ok(graph)
+    }
+}
+
+impl<T, W> Graph<T, W>
+where
+    W: Clone + Default,
+{
+    /// Renders the graph as an adjacency matrix, using `W::default()` for absent edges.
+    ///
+    pub fn as_adjacency_matrix(&self) -> Matrix<W> {
+        let n = self.nodes.len();
+        let mut m = Matrix::<W>::new(n, n);
+        for (&from, list) in &self.links {
+            for edge in list {
+                if let Some(weight) = &edge.weight {
+                    m.set(from, edge.linked, weight.clone());
+                }
+            }
+        }
+        m
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Graph;
@@ -200,6 +529,149 @@ mod tests {
         assert_eq!(g.edges_count(), 1);
     }
 
+    #[test]
+    fn connected_components_ok() {
+        let mut g = Graph::<&str, usize>::new(false);
+        let a = g.add_node(Some("a"), None, None);
+        let b = g.add_node(Some("b"), Some(a), None);
+        let c = g.add_node(Some("c"), None, None);
+
+        let components = g.connected_components();
+        assert_eq!(components[a], components[b]);
+        assert_ne!(components[a], components[c]);
+    }
+
+    #[test]
+    fn mst_kruskal_ok() {
+        let mut g = Graph::<&str, u32>::new(false);
+        let a = g.add_node(None, None, None);
+        let b = g.add_node(None, Some(a), Some(1));
+        let c = g.add_node(None, Some(a), Some(5));
+        g.add_link(b, c, Some(2));
+
+        let mst = g.mst_kruskal().unwrap();
+        let total: u32 = mst.iter().map(|(_, _, w)| *w).sum();
+        assert_eq!(mst.len(), 2);
+        assert_eq!(total, 3);
+    }
+
+    #[test]
+    fn mst_kruskal_oriented_err() {
+        let g = Graph::<&str, u32>::new(true);
+        assert!(g.mst_kruskal().is_err());
+    }
+
+    #[test]
+    fn mst_prim_ok() {
+        let mut g = Graph::<&str, u32>::new(false);
+        let a = g.add_node(None, None, None);
+        let b = g.add_node(None, Some(a), Some(1));
+        let c = g.add_node(None, Some(a), Some(5));
+        g.add_link(b, c, Some(2));
+
+        let (mst, total) = g.mst_prim(a).unwrap();
+        assert_eq!(mst.len(), 2);
+        assert_eq!(total, 3);
+    }
+
+    #[test]
+    fn adjacency_list_ok() {
+        let mut g = Graph::<&str, usize>::new(false);
+        let a = g.add_node(None, None, None);
+        let b = g.add_node(None, Some(a), None);
+
+        let adj = g.adjacency_list();
+        assert_eq!(adj[a], vec![b]);
+        assert_eq!(adj[b], vec![a]);
+    }
+
+    #[test]
+    fn bfs_ok() {
+        let mut g = Graph::<&str, usize>::new(false);
+        let a = g.add_node(None, None, None);
+        let b = g.add_node(None, Some(a), None);
+        let c = g.add_node(None, Some(a), None);
+        g.add_node(None, Some(b), None);
+
+        let order = g.bfs(a);
+        assert_eq!(order[0], a);
+        assert_eq!(order.len(), 4);
+        assert!(order.contains(&b));
+        assert!(order.contains(&c));
+    }
+
+    #[test]
+    fn dfs_ok() {
+        let mut g = Graph::<&str, usize>::new(false);
+        let a = g.add_node(None, None, None);
+        g.add_node(None, Some(a), None);
+        g.add_node(None, Some(a), None);
+
+        let order = g.dfs(a);
+        assert_eq!(order[0], a);
+        assert_eq!(order.len(), 3);
+    }
+
+    #[test]
+    fn shortest_path_ok() {
+        let mut g = Graph::<&str, u32>::new(false);
+        let a = g.add_node(None, None, None);
+        let b = g.add_node(None, Some(a), Some(5));
+        let c = g.add_node(None, Some(a), Some(1));
+        g.add_link(c, b, Some(1));
+
+        let (path, total) = g.shortest_path(a, b).unwrap();
+        assert_eq!(path, vec![a, c, b]);
+        assert_eq!(total, 2);
+    }
+
+    #[test]
+    fn shortest_path_unreachable() {
+        let mut g = Graph::<&str, u32>::new(true);
+        let a = g.add_node(None, None, None);
+        let b = g.add_node(None, None, None);
+        assert!(g.shortest_path(a, b).is_none());
+    }
+
+    #[test]
+    fn from_adjacency_matrix_ok() {
+        let text = "0 1 0\n1 0 2\n0 2 0";
+        let g = Graph::<(), u32>::from_adjacency_matrix(text, false).unwrap();
+        assert_eq!(g.nodes_count(), 3);
+        assert_eq!(g.edges_count(), 2);
+    }
+
+    #[test]
+    fn from_adjacency_matrix_not_square_err() {
+        let text = "0 1\n1 0 0";
+        assert!(Graph::<(), u32>::from_adjacency_matrix(text, false).is_err());
+    }
+
+    #[test]
+    fn from_adjacency_matrix_asymmetric_err() {
+        let text = "0 1 0\n2 0 2\n0 2 0";
+        assert!(Graph::<(), u32>::from_adjacency_matrix(text, false).is_err());
+    }
+
+    #[test]
+    fn from_adjacency_matrix_oriented_allows_asymmetric_ok() {
+        let text = "0 1 0\n2 0 2\n0 2 0";
+        let g = Graph::<(), u32>::from_adjacency_matrix(text, true).unwrap();
+        assert_eq!(g.nodes_count(), 3);
+        assert_eq!(g.edges_count(), 4);
+    }
+
+    #[test]
+    fn as_adjacency_matrix_ok() {
+        let mut g = Graph::<&str, u32>::new(true);
+        let a = g.add_node(None, None, None);
+        let b = g.add_node(None, Some(a), Some(7));
+
+        let m = g.as_adjacency_matrix();
+        assert_eq!(m.get(a, b), &7);
+        assert_eq!(m.get(b, a), &0);
+    }
+
     // #[test]
     // fn remove_node_ok() {
     //     let mut g = Graph::<&str, usize>::new(true);
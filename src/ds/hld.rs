@@ -0,0 +1,157 @@
+use std::ops::RangeInclusive;
+
+/// Heavy-light decomposition of a tree, enabling a path query between any
+/// two nodes to be broken into `O(log n)` contiguous index ranges.
+///
+/// Built from a `root` node and the tree's adjacency lists (see
+/// `Graph::adjacency_list`).
+pub struct Hld {
+    parent: Vec<usize>,
+    depth: Vec<usize>,
+    head: Vec<usize>,
+    ord: Vec<usize>,
+}
+
+impl Hld {
+    /// Builds the decomposition rooted at `root` from the tree's adjacency lists.
+    ///
+    pub fn new(root: usize, adj: &[Vec<usize>]) -> Hld {
+        let n = adj.len();
+        let mut parent = vec![root; n];
+        let mut depth = vec![0usize; n];
+        let mut size = vec![1usize; n];
+        let mut heavy: Vec<Option<usize>> = vec![None; n];
+        let mut head = vec![root; n];
+        let mut ord = vec![0usize; n];
+
+        compute_sizes(root, root, 0, adj, &mut parent, &mut depth, &mut size, &mut heavy);
+
+        let mut pos = 0usize;
+        decompose(root, root, adj, &parent, &heavy, &mut head, &mut ord, &mut pos);
+
+        Hld { parent, depth, head, ord }
+    }
+
+    /// Yields the `[l..=r]` index intervals (in decomposition order) covering the path `u`–`v`.
+    ///
+    /// Repeatedly hops the deeper chain head up to its parent until `u` and
+    /// `v` land on the same chain, then emits the final interval between them.
+    pub fn iter_path(&self, u: usize, v: usize) -> Vec<RangeInclusive<usize>> {
+        let mut u = u;
+        let mut v = v;
+        let mut intervals = Vec::new();
+
+        while self.head[u] != self.head[v] {
+            if self.depth[self.head[u]] < self.depth[self.head[v]] {
+                std::mem::swap(&mut u, &mut v);
+            }
+            let h = self.head[u];
+            intervals.push(self.ord[h]..=self.ord[u]);
+            u = self.parent[h];
+        }
+
+        if self.ord[u] > self.ord[v] {
+            std::mem::swap(&mut u, &mut v);
+        }
+        intervals.push(self.ord[u]..=self.ord[v]);
+
+        intervals
+    }
+}
+
+fn compute_sizes(
+    node: usize,
+    par: usize,
+    d: usize,
+    adj: &[Vec<usize>],
+    parent: &mut [usize],
+    depth: &mut [usize],
+    size: &mut [usize],
+    heavy: &mut [Option<usize>],
+) {
+    parent[node] = par;
+    depth[node] = d;
+    size[node] = 1;
+
+    let mut max_child_size = 0;
+    for &child in &adj[node] {
+        if child != par {
+            compute_sizes(child, node, d + 1, adj, parent, depth, size, heavy);
+            size[node] += size[child];
+            if size[child] > max_child_size {
+                max_child_size = size[child];
+                heavy[node] = Some(child);
+            }
+        }
+    }
+}
+
+fn decompose(
+    node: usize,
+    chain_head: usize,
+    adj: &[Vec<usize>],
+    parent: &[usize],
+    heavy: &[Option<usize>],
+    head: &mut [usize],
+    ord: &mut [usize],
+    pos: &mut usize,
+) {
+    head[node] = chain_head;
+    ord[node] = *pos;
+    *pos += 1;
+
+    if let Some(heavy_child) = heavy[node] {
+        decompose(heavy_child, chain_head, adj, parent, heavy, head, ord, pos);
+    }
+    for &child in &adj[node] {
+        if child != parent[node] && Some(child) != heavy[node] {
+            decompose(child, child, adj, parent, heavy, head, ord, pos);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Hld;
+
+    // Tree:
+    //       0
+    //      / \
+    //     1   2
+    //    / \
+    //   3   4
+    fn sample_adj() -> Vec<Vec<usize>> {
+        vec![
+            vec![1, 2],
+            vec![0, 3, 4],
+            vec![0],
+            vec![1],
+            vec![1],
+        ]
+    }
+
+    #[test]
+    fn new_ok() {
+        let hld = Hld::new(0, &sample_adj());
+        assert_eq!(hld.parent[1], 0);
+        assert_eq!(hld.parent[3], 1);
+        assert_eq!(hld.depth, vec![0, 1, 1, 2, 2]);
+    }
+
+    #[test]
+    fn iter_path_same_node_ok() {
+        let hld = Hld::new(0, &sample_adj());
+        let intervals = hld.iter_path(3, 3);
+        assert_eq!(intervals.len(), 1);
+        assert_eq!(*intervals[0].start(), *intervals[0].end());
+    }
+
+    #[test]
+    fn iter_path_across_chains_ok() {
+        let hld = Hld::new(0, &sample_adj());
+        let intervals = hld.iter_path(3, 2);
+        // 3 and 2 sit on different heavy chains, so the path needs more
+        // than one contiguous interval to cover.
+        assert!(intervals.len() >= 2);
+    }
+}
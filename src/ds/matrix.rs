@@ -1,7 +1,50 @@
+use std::convert::TryInto;
 use std::ops::{Index, IndexMut};
+use std::ptr::NonNull;
 use std::slice::{Iter, IterMut};
 use std::{alloc, fmt, mem, ops};
 
+use crate::ds::errors::{MatrixBytesError, MatrixError};
+
+/// Magic bytes identifying the `Matrix::to_bytes` on-disk format.
+const MAGIC: &[u8; 4] = b"MTRX";
+
+/// On-disk format version, bumped on incompatible header changes.
+const FORMAT_VERSION: u8 = 1;
+
+/// Header length: `MAGIC` (4) + version (1) + rows (4) + cols (4) + elem size (4).
+const HEADER_LEN: usize = 4 + 1 + 4 + 4 + 4;
+
+/// Types that can be losslessly (de)serialized into a matrix's fixed-width binary payload.
+///
+/// Implemented for the built-in integer and floating-point primitives via
+/// their big-endian byte representation.
+pub trait MatrixBytes: Sized {
+    /// Writes `self` into `buf` as big-endian bytes. `buf` is exactly `size_of::<Self>()` long.
+    fn write_be_bytes(&self, buf: &mut [u8]);
+
+    /// Reads a value from `buf`, which is exactly `size_of::<Self>()` long.
+    fn read_be_bytes(buf: &[u8]) -> Self;
+}
+
+macro_rules! impl_matrix_bytes {
+    ($($t:ty),*) => {
+        $(
+            impl MatrixBytes for $t {
+                fn write_be_bytes(&self, buf: &mut [u8]) {
+                    buf.copy_from_slice(&self.to_be_bytes());
+                }
+
+                fn read_be_bytes(buf: &[u8]) -> Self {
+                    <$t>::from_be_bytes(buf.try_into().unwrap())
+                }
+            }
+        )*
+    };
+}
+
+impl_matrix_bytes!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, f32, f64);
+
 /// Rectangular table of elements (two-dimensional array).
 ///
 pub struct Matrix<'a, T>
@@ -20,12 +63,29 @@ where
     ///
     /// `rows` - rows number.
     /// `cols` - columns number.
-    /// Panic, if memory allocation is not succesfully.
+    /// Panics if `rows`/`cols` are zero, `rows * cols` overflows `usize`,
+    /// or memory allocation fails. See `try_new` for a fallible version.
     pub fn new(rows: usize, cols: usize) -> Self {
-        Self {
-            cols,
-            buffer: Self::alloc(rows, cols),
+        Self::try_new(rows, cols).expect("failed to create matrix")
+    }
+
+    /// Creates new Matrix and fills it with default values.
+    ///
+    /// `rows` - rows number.
+    /// `cols` - columns number.
+    /// Returns `MatrixError` if `rows`/`cols` are zero, `rows * cols`
+    /// overflows `usize`, or the backing memory can't be allocated.
+    pub fn try_new(rows: usize, cols: usize) -> Result<Self, MatrixError> {
+        if rows == 0 || cols == 0 {
+            return Err(MatrixError::new("rows and cols must both be non-zero"));
         }
+        let count = rows
+            .checked_mul(cols)
+            .ok_or_else(|| MatrixError::new("rows * cols overflows usize"))?;
+        Ok(Self {
+            cols,
+            buffer: Self::try_alloc(count)?,
+        })
     }
 
     /// Fills matrix with a default values.
@@ -94,12 +154,39 @@ where
 
     /// Memory allocation for data buffer.
     ///
-    fn alloc(rows: usize, cols: usize) -> &'a mut [T] {
+    /// Panics if allocation fails; see `try_alloc` for a fallible version.
+    fn alloc(count: usize) -> &'a mut [T] {
+        Self::try_alloc(count).expect("failed to allocate matrix buffer")
+    }
+
+    /// Fallible memory allocation for data buffer.
+    ///
+    /// A zero-size `Layout` is handled specially: the global allocator's
+    /// contract forbids passing one to `alloc::alloc`, so a
+    /// dangling-but-aligned pointer is used instead. This happens both
+    /// when `count == 0` and when `T` is a zero-sized type (`count` any
+    /// value, `size_of::<T>() == 0`), since either way the byte size is 0.
+    fn try_alloc(count: usize) -> Result<&'a mut [T], MatrixError> {
+        if count == 0 || mem::size_of::<T>() == 0 {
+            let ptr = NonNull::<T>::dangling().as_ptr();
+            let slice = unsafe { std::slice::from_raw_parts_mut(ptr, count) };
+            Self::init_with(slice, T::default());
+            return Ok(slice);
+        }
+
+        if count.checked_mul(mem::size_of::<T>()).is_none() {
+            return Err(MatrixError::new("buffer byte size overflows usize"));
+        }
+        let layout = layout::<T>(count)
+            .map_err(|e| MatrixError::new(&format!("invalid memory layout: {}", e)))?;
         unsafe {
-            let buf = alloc::alloc(layout::<T>(rows * cols).unwrap()) as *mut T;
-            let slice = std::slice::from_raw_parts_mut(buf, rows * cols);
-            Self::fill_with(slice, T::default());
-            slice
+            let buf = alloc::alloc(layout) as *mut T;
+            if buf.is_null() {
+                return Err(MatrixError::new("memory allocation failed"));
+            }
+            let slice = std::slice::from_raw_parts_mut(buf, count);
+            Self::init_with(slice, T::default());
+            Ok(slice)
         }
     }
 
@@ -111,6 +198,19 @@ where
         }
     }
 
+    /// Initializes freshly-allocated, not-yet-valid memory with `value`.
+    ///
+    /// Unlike `fill_with`, this never reads or drops the slot's previous
+    /// contents: `buf` is raw memory from `alloc::alloc` (or a dangling
+    /// zero-size placeholder), so `*e = ...` would run `Drop` glue over
+    /// garbage. `ptr::write` installs the value without touching what was
+    /// there before.
+    fn init_with(buf: &mut [T], value: T) {
+        for e in buf {
+            unsafe { std::ptr::write(e, value.clone()) };
+        }
+    }
+
     fn linear_index(&self, row: usize, col: usize) -> usize {
         if row >= self.rows() || col >= self.cols {
             panic!("index out of bounds");
@@ -123,11 +223,103 @@ where
     }
 }
 
+impl<'a, T> Matrix<'a, T>
+where
+    T: Default + Clone + MatrixBytes,
+{
+    /// Serializes the matrix to a compact, self-describing binary format.
+    ///
+    /// Layout: 4-byte magic, 1-byte version, big-endian `u32` rows, `u32`
+    /// cols, `u32` element size, followed by the elements themselves in
+    /// line traversal order, each encoded via `MatrixBytes`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let elem_size = mem::size_of::<T>();
+        let mut bytes = Vec::with_capacity(HEADER_LEN + self.elements_number() * elem_size);
+
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(FORMAT_VERSION);
+        bytes.extend_from_slice(&(self.rows() as u32).to_be_bytes());
+        bytes.extend_from_slice(&(self.cols() as u32).to_be_bytes());
+        bytes.extend_from_slice(&(elem_size as u32).to_be_bytes());
+
+        let mut elem_buf = vec![0u8; elem_size];
+        for e in self.buffer.iter() {
+            e.write_be_bytes(&mut elem_buf);
+            bytes.extend_from_slice(&elem_buf);
+        }
+
+        bytes
+    }
+
+    /// Deserializes a matrix previously produced by `to_bytes`.
+    ///
+    /// Validates the magic, version, that the stored element size matches
+    /// `size_of::<T>()`, and that `rows * cols * elem_size` exactly
+    /// accounts for the remaining payload, returning `MatrixBytesError` on
+    /// any mismatch or truncation rather than reading out of bounds.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, MatrixBytesError> {
+        if bytes.len() < HEADER_LEN {
+            return Err(MatrixBytesError::new("buffer too short to contain a header"));
+        }
+        if &bytes[0..4] != MAGIC {
+            return Err(MatrixBytesError::new("bad magic, not a Matrix byte stream"));
+        }
+
+        let version = bytes[4];
+        if version != FORMAT_VERSION {
+            return Err(MatrixBytesError::new(&format!(
+                "unsupported format version {}, expected {}",
+                version, FORMAT_VERSION
+            )));
+        }
+
+        let rows = u32::from_be_bytes(bytes[5..9].try_into().unwrap()) as usize;
+        let cols = u32::from_be_bytes(bytes[9..13].try_into().unwrap()) as usize;
+        let elem_size = u32::from_be_bytes(bytes[13..17].try_into().unwrap()) as usize;
+
+        if elem_size != mem::size_of::<T>() {
+            return Err(MatrixBytesError::new(&format!(
+                "stored element size {} does not match {} bytes of the target type",
+                elem_size,
+                mem::size_of::<T>()
+            )));
+        }
+
+        let payload = &bytes[HEADER_LEN..];
+        let expected_len = rows
+            .checked_mul(cols)
+            .and_then(|n| n.checked_mul(elem_size))
+            .ok_or_else(|| MatrixBytesError::new("rows * cols * elem_size overflows usize"))?;
+
+        if payload.len() != expected_len {
+            return Err(MatrixBytesError::new(&format!(
+                "expected {} bytes of payload, found {}",
+                expected_len,
+                payload.len()
+            )));
+        }
+
+        let mut matrix =
+            Self::try_new(rows, cols).map_err(|e| MatrixBytesError::new(&e.message))?;
+        for (idx, chunk) in payload.chunks_exact(elem_size).enumerate() {
+            matrix.buffer[idx] = T::read_be_bytes(chunk);
+        }
+
+        Ok(matrix)
+    }
+}
+
 impl<'a, T> Drop for Matrix<'a, T>
 where
     T: Default + Clone,
 {
     fn drop(&mut self) {
+        // No allocation was ever made for an empty buffer or a zero-sized
+        // `T`, since `try_alloc` hands out a dangling pointer in both
+        // cases; deallocating it would be UB.
+        if self.buffer.is_empty() || mem::size_of::<T>() == 0 {
+            return;
+        }
         unsafe {
             alloc::dealloc(
                 self.buffer.as_mut_ptr() as *mut u8,
@@ -179,7 +371,7 @@ where
     T: Default + Clone,
 {
     fn clone(&self) -> Self {
-        let new_buf = Self::alloc(self.rows(), self.cols());
+        let new_buf = Self::alloc(self.buffer.len());
         for idx in 0..self.buffer.len() {
             new_buf[idx] = self.buffer[idx].clone();
         }
@@ -442,6 +634,72 @@ mod tests {
         assert_eq!(m[0][0], 70);
     }
 
+    #[test]
+    fn try_new_zero_dims_err() {
+        assert!(Matrix::<i32>::try_new(0, 5).is_err());
+        assert!(Matrix::<i32>::try_new(5, 0).is_err());
+    }
+
+    #[test]
+    fn try_new_overflow_err() {
+        assert!(Matrix::<i32>::try_new(usize::MAX, 2).is_err());
+    }
+
+    #[test]
+    fn try_new_ok() {
+        let m = Matrix::<i32>::try_new(2, 3).unwrap();
+        assert_eq_all::<i32>(&m, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_zero_dims_panics() {
+        Matrix::<i32>::new(0, 0);
+    }
+
+    #[test]
+    fn try_new_zero_sized_type_ok() {
+        // `size_of::<()>() == 0`, so the byte layout is zero-size even
+        // though `count` isn't; must not reach `alloc::alloc`.
+        let mut m = Matrix::<()>::try_new(2, 3).unwrap();
+        assert_eq!(m.elements_number(), 6);
+        m.fill(());
+        assert_eq!(*m.get(1, 2), ());
+    }
+
+    #[test]
+    fn to_bytes_from_bytes_ok() {
+        let mut a = Matrix::<i32>::new(2, 3);
+        for (idx, e) in a.iter_mut().enumerate() {
+            *e = idx as i32 * 10;
+        }
+        let bytes = a.to_bytes();
+        let b = Matrix::<i32>::from_bytes(&bytes).unwrap();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn from_bytes_bad_magic_err() {
+        let a = Matrix::<i32>::new(2, 3);
+        let mut bytes = a.to_bytes();
+        bytes[0] = b'X';
+        assert!(Matrix::<i32>::from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn from_bytes_elem_size_mismatch_err() {
+        let a = Matrix::<i32>::new(2, 3);
+        let bytes = a.to_bytes();
+        assert!(Matrix::<i64>::from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn from_bytes_truncated_err() {
+        let a = Matrix::<i32>::new(2, 3);
+        let bytes = a.to_bytes();
+        assert!(Matrix::<i32>::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+
     fn assert_eq_all<T: Default + Clone + PartialEq + Debug>(m: &Matrix<T>, value: T) {
         for i in 0..m.rows() {
             for j in 0..m.cols() {
@@ -0,0 +1,108 @@
+use std::ops::Range;
+
+/// Generic segment tree supporting O(log n) range aggregate queries and
+/// point updates, parameterized by a monoid: an `identity` element and an
+/// associative `combine` operation (min/max/sum/gcd all drop in by
+/// choosing the right closure).
+pub struct SegTree<T, F>
+where
+    F: Fn(&T, &T) -> T,
+{
+    n: usize,
+    tree: Vec<T>,
+    identity: T,
+    combine: F,
+}
+
+impl<T, F> SegTree<T, F>
+where
+    T: Clone,
+    F: Fn(&T, &T) -> T,
+{
+    /// Builds a segment tree over `values`.
+    ///
+    /// `identity` must be the neutral element of `combine`
+    /// (`combine(identity, x) == x` for every `x`).
+    pub fn from_slice(values: &[T], identity: T, combine: F) -> SegTree<T, F> {
+        let n = values.len().next_power_of_two();
+        let mut tree = vec![identity.clone(); 2 * n];
+
+        for (i, value) in values.iter().enumerate() {
+            tree[n + i] = value.clone();
+        }
+        for i in (1..n).rev() {
+            tree[i] = combine(&tree[2 * i], &tree[2 * i + 1]);
+        }
+
+        SegTree { n, tree, identity, combine }
+    }
+
+    /// Sets the value at `index` and recombines ancestors up to the root.
+    ///
+    pub fn update(&mut self, index: usize, value: T) {
+        let mut i = index + self.n;
+        self.tree[i] = value;
+
+        i /= 2;
+        while i >= 1 {
+            self.tree[i] = (self.combine)(&self.tree[2 * i], &self.tree[2 * i + 1]);
+            i /= 2;
+        }
+    }
+
+    /// Combines every element in the half-open `range`.
+    ///
+    pub fn query(&self, range: Range<usize>) -> T {
+        let mut lo = range.start + self.n;
+        let mut hi = range.end + self.n;
+        let mut left_acc = self.identity.clone();
+        let mut right_acc = self.identity.clone();
+
+        while lo < hi {
+            if lo % 2 == 1 {
+                left_acc = (self.combine)(&left_acc, &self.tree[lo]);
+                lo += 1;
+            }
+            if hi % 2 == 1 {
+                hi -= 1;
+                right_acc = (self.combine)(&self.tree[hi], &right_acc);
+            }
+            lo /= 2;
+            hi /= 2;
+        }
+
+        (self.combine)(&left_acc, &right_acc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SegTree;
+
+    #[test]
+    fn sum_query_ok() {
+        let values = [1, 2, 3, 4, 5];
+        let tree = SegTree::from_slice(&values, 0, |a, b| a + b);
+        assert_eq!(tree.query(0..5), 15);
+        assert_eq!(tree.query(1..3), 5);
+        assert_eq!(tree.query(2..2), 0);
+    }
+
+    #[test]
+    fn min_query_ok() {
+        let values = [5, 2, 8, 1, 9];
+        let tree = SegTree::from_slice(&values, i32::MAX, |a, b| *a.min(b));
+        assert_eq!(tree.query(0..5), 1);
+        assert_eq!(tree.query(0..2), 2);
+    }
+
+    #[test]
+    fn update_ok() {
+        let values = [1, 2, 3, 4];
+        let mut tree = SegTree::from_slice(&values, 0, |a, b| a + b);
+        assert_eq!(tree.query(0..4), 10);
+        tree.update(1, 20);
+        assert_eq!(tree.query(0..4), 28);
+        assert_eq!(tree.query(1..2), 20);
+    }
+}
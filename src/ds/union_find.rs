@@ -0,0 +1,85 @@
+/// Disjoint-set (union-find) structure with path compression and union by size.
+///
+/// Supports near-constant amortized `find` and `union` operations over a
+/// fixed universe of `0..n` elements.
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl UnionFind {
+    /// Creates a new `UnionFind` over `n` singleton sets `{0}, {1}, ..., {n-1}`.
+    ///
+    pub fn new(n: usize) -> UnionFind {
+        UnionFind {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+        }
+    }
+
+    /// Finds the representative (root) of the set containing `x`.
+    ///
+    /// Applies path compression: every visited node is re-pointed directly
+    /// to the root.
+    pub fn find(&mut self, x: usize) -> usize {
+        if self.parent[x] != x {
+            self.parent[x] = self.find(self.parent[x]);
+        }
+        self.parent[x]
+    }
+
+    /// Merges the sets containing `a` and `b`.
+    ///
+    /// The smaller set is attached under the root of the bigger one.
+    /// Does nothing if `a` and `b` already belong to the same set.
+    pub fn union(&mut self, a: usize, b: usize) {
+        let mut ra = self.find(a);
+        let mut rb = self.find(b);
+        if ra == rb {
+            return;
+        }
+        if self.size[ra] < self.size[rb] {
+            std::mem::swap(&mut ra, &mut rb);
+        }
+        self.parent[rb] = ra;
+        self.size[ra] += self.size[rb];
+    }
+
+    /// Checks if `a` and `b` belong to the same set.
+    ///
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnionFind;
+
+    #[test]
+    fn new_ok() {
+        let mut uf = UnionFind::new(5);
+        for i in 0..5 {
+            assert_eq!(uf.find(i), i);
+        }
+    }
+
+    #[test]
+    fn union_and_same_ok() {
+        let mut uf = UnionFind::new(5);
+        assert!(!uf.same(0, 1));
+        uf.union(0, 1);
+        assert!(uf.same(0, 1));
+        uf.union(1, 2);
+        assert!(uf.same(0, 2));
+        assert!(!uf.same(0, 3));
+    }
+
+    #[test]
+    fn union_idempotent_ok() {
+        let mut uf = UnionFind::new(3);
+        uf.union(0, 1);
+        uf.union(0, 1);
+        assert!(uf.same(0, 1));
+    }
+}
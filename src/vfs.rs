@@ -1,12 +1,14 @@
 //! Virtual file system allows you to work with relative file paths in a convenient way.
 
 mod errors;
+mod path;
 
 use std::fs;
 use std::io::{Error, ErrorKind, Result};
 use std::path::{Path, PathBuf, Component};
 
-use errors::{NotAbsolutePathError, NotRelativePathError, PathNotBelongsError};
+use errors::PathNotBelongsError;
+pub use path::VfsPath;
 
 /// A reference to an virtual file system.
 pub struct VirtualFileSystem {
@@ -30,276 +32,345 @@ impl VirtualFileSystem {
     /// Change current `root`.
     ///
     /// A `new_root` path may be absolute or relative and it must exists.
-//    pub fn chroot<P: AsRef<Path>>(&mut self, new_root: P) -> Result<()> {
-//        self.root = if new_root.as_ref().is_absolute() {
-//            new_root.as_ref().canonicalize()?
-//        } else {
-//            self.absolute(new_root)?
-//        };
-//        Ok(())
-//    }
+    /// The resolved target must stay within the current `root`, otherwise
+    /// a `PathNotBelongsError` is returned.
+    pub fn chroot<P: AsRef<Path>>(&mut self, new_root: P) -> Result<()> {
+        let new_root = new_root.as_ref();
+        let candidate = if new_root.is_absolute() {
+            new_root.canonicalize()?
+        } else {
+            self.root.join(new_root).canonicalize()?
+        };
+        if !candidate.starts_with(&self.root) {
+            return Err(Error::new(
+                ErrorKind::Other,
+                PathNotBelongsError::new(&candidate),
+            ));
+        }
+        self.root = candidate;
+        Ok(())
+    }
 
     /// Convert relative `path` to absolute.
     ///
-    /// If `path` is absolute and starts with current `root`, then return it.
-    /// If `path` is relative, then append it to the end of the current `root` and return joined path.
-    /// If joined path in last case is not exists, then `io::Error` will occure.
-//    pub fn absolute<P: AsRef<Path>>(&self, path: P) -> Option<PathBuf> {
-//        if path.as_ref().is_relative() {
-//            self.root.join(path.as_ref())
-//        }
-//        //let pb =
-//            if path.as_ref().is_absolute() {
-//            if self.contains(path.as_ref()) {
-//                path.as_ref()
-//                    .to_path_buf()
-//
-//            } else {
-//                None
-//            }
-//        } else {
-//            self.root
-//                .join(path.as_ref())
-//
-//        }
-//                .canonicalize()
-//            .ok()
-//    }
-
-    /// Convert absolute `path` to relative.
+    /// Purely lexical: never touches the filesystem, so it also works for
+    /// paths that don't exist yet. If `path` escapes `root` (via `..` or an
+    /// absolute path outside `root`), the result is clamped to `root`.
+    pub fn absolute<P: AsRef<Path>>(&self, path: P) -> PathBuf {
+        self.root.join(self.normalize(path).clamped().as_relative_str())
+    }
+
+    /// Convert `path` to a root-relative `VfsPath`.
     ///
-    /// If `path` is not absolute, then return `io::Error`.
-    /// If `path` is equal to `root`, then return `.` (current).
-    /// If `root` equals to `/foo/bar` and `path` equals to `/foo/bar/more`, then return `more`.
-//    pub fn relative<P: AsRef<Path>>(&self, path: P) -> Result<PathBuf> {
-//        let path = path.as_ref();
-//        if path.is_absolute() {
-//            if path == self.root {
-//                return Ok(PathBuf::from("."));
-//            } else {
-//                if self.contains(path) {
-//                    return Ok(path.strip_prefix(&self.root).unwrap().to_path_buf());
-//                }
-//            }
-//        }
-//        Err(Error::new(ErrorKind::Other, NotAbsolutePathError::new()))
-//    }
+    /// The `path` is normalized first, so this also jails escaping paths to
+    /// `root`. Resolves to the root `VfsPath` when `path` resolves to `root` itself.
+    pub fn relative<P: AsRef<Path>>(&self, path: P) -> VfsPath {
+        self.normalize(path).clamped()
+    }
 
+    /// Verifies, that the `path` belongs to the virtual file system.
+    ///
+    /// Unlike `relative`/`absolute`, this does not clamp: a `path` with
+    /// unresolved leading `..` hops left after normalization actually
+    /// escapes `root`, so it is reported as not contained.
+    pub fn contains<P: AsRef<Path>>(&self, path: P) -> bool {
+        self.normalize(path).supers() == 0
+    }
 
+    /// Returns whether `path` exists within the virtual file system.
+    ///
+    pub fn exists<P: AsRef<Path>>(&self, path: P) -> bool {
+        let path = path.as_ref();
+        self.contains(path) && self.absolute(path).exists()
+    }
 
     /// Creates a new, empty directory at the provided path.
     ///
-//    pub fn create_dir<P: AsRef<Path>>(&self, path: P) -> Result<()> {
-//        if !self.contains(path.as_ref()) {
-//            return Err(Error::new(
-//                ErrorKind::Other,
-//                PathNotBelongsError::new(path.as_ref()),
-//            ));
-//        }
-//        if path.as_ref().is_absolute() {
-//            fs::create_dir(self.root.join(self.relative(path)?))
-//        } else {
-//            fs::create_dir(self.root.join(path.as_ref()))
-//        }
-//    }
+    pub fn create_dir<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        fs::create_dir(self.resolve(path)?)
+    }
 
     /// Recursively create a directory and all of its parent components if they are missing.
     ///
-//    pub fn create_dir_all<P: AsRef<Path>>(&self, path: P) -> Result<()> {
-//        if !self.contains(path.as_ref()) {
-//            return Err(Error::new(
-//                ErrorKind::Other,
-//                PathNotBelongsError::new(path.as_ref()),
-//            ));
-//        }
-//        if path.as_ref().is_absolute() {
-//            fs::create_dir_all(self.root.join(self.relative(path)?))
-//        } else {
-//            fs::create_dir_all(self.root.join(path.as_ref()))
-//        }
-//    }
+    pub fn create_dir_all<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        fs::create_dir_all(self.resolve(path)?)
+    }
 
     /// Removes a directory at this path, after removing all its contents. Use carefully!
     ///
-//    pub fn remove_dir_all<P: AsRef<Path>>(&self, path: P) -> Result<()> {
-//        if !self.contains(path.as_ref()) {
-//            return Err(Error::new(
-//                ErrorKind::Other,
-//                PathNotBelongsError::new(path.as_ref()),
-//            ));
-//        }
-//        if self.exists(path.as_ref()) {
-//            if path.as_ref().is_absolute() {
-//                fs::remove_dir_all(path)?;
-//            } else {
-//                fs::remove_dir_all(self.root.join(path.as_ref()))?;
-//            }
-//        }
-//        Ok(())
-//    }
+    pub fn remove_dir_all<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        fs::remove_dir_all(self.resolve(path)?)
+    }
 
-    /// Verifies, that the `path` belongs to the virtual file system.
+    /// Reads the entire contents of a file into a byte vector.
     ///
-//    fn contains<P: AsRef<Path>>(&self, path: P) -> bool {
-//        if path.as_ref().is_absolute() {
-//            path.as_ref().starts_with(&self.root)
-//        } else {
-//            true
-//        }
-//    }
-
-    fn normalize<P: AsRef<Path>>(&self, path: P) -> PathBuf {
-
-        // TODO: error, if path is empty
-
-        let mut normalized = PathBuf::new();
-        match path.as_ref().components().nth(0).unwrap() {
-            Component::CurDir => {
-                normalized.push(&self.root);
-                if path.as_ref().components().count() > 1 {
-                    normalized.push(path.as_ref().strip_prefix(Component::CurDir).unwrap())
-                }
-            },
-            Component::ParentDir => {
-                if self.root.components().count() == 1 {
-                    normalized.push(&self.root)
-                } else {
-                    normalized.push(&self.root.parent().unwrap())
-                }
-                if path.as_ref().components().count() > 1 {
-                    normalized.push(path.as_ref().strip_prefix(Component::ParentDir).unwrap())
-                }
-            },
-            _ => ()
-        };
-        for component in path.as_ref().components() {
+    pub fn read<P: AsRef<Path>>(&self, path: P) -> Result<Vec<u8>> {
+        fs::read(self.resolve(path)?)
+    }
+
+    /// Writes `contents` to a file, creating it if it doesn't exist and truncating it otherwise.
+    ///
+    pub fn write<P: AsRef<Path>, C: AsRef<[u8]>>(&self, path: P, contents: C) -> Result<()> {
+        fs::write(self.resolve(path)?, contents)
+    }
+
+    /// Removes a file.
+    ///
+    pub fn remove_file<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        fs::remove_file(self.resolve(path)?)
+    }
+
+    /// Lists the entries of a directory as root-relative `VfsPath`s.
+    ///
+    pub fn read_dir<P: AsRef<Path>>(&self, path: P) -> Result<Vec<VfsPath>> {
+        let dir = self.resolve(path)?;
+        fs::read_dir(dir)?
+            .map(|entry| entry.map(|entry| self.relative(entry.path())))
+            .collect()
+    }
+
+    /// Resolves `path` to an absolute filesystem path, rejecting anything that escapes `root`.
+    ///
+    fn resolve<P: AsRef<Path>>(&self, path: P) -> Result<PathBuf> {
+        let path = path.as_ref();
+        if !self.contains(path) {
+            return Err(Error::new(ErrorKind::Other, PathNotBelongsError::new(path)));
+        }
+        Ok(self.absolute(path))
+    }
 
+    /// Lexically normalizes `path` against `root`.
+    ///
+    /// Never touches the filesystem, so it works for not-yet-created paths.
+    /// An input that begins with `RootDir`/`Prefix` is only honored if it
+    /// already starts with `root`; otherwise it's rejected and treated as
+    /// an escaping path (one leading `super`, since it names something
+    /// outside `root`). Otherwise `path` is re-assembled from its components
+    /// (dropping repeated and trailing separators) and parsed as a `VfsPath`,
+    /// keeping any unresolved leading `..` hops as `supers` rather than
+    /// discarding them, so callers can tell an escaping path from a
+    /// contained one. A path that is not valid UTF-8 is treated as escaping.
+    /// Use `clamped()` on the result where a concrete in-jail path is
+    /// actually needed.
+    fn normalize<P: AsRef<Path>>(&self, path: P) -> VfsPath {
+        let path = path.as_ref();
+
+        let is_anchored = matches!(
+            path.components().next(),
+            Some(Component::RootDir) | Some(Component::Prefix(_))
+        );
+
+        if is_anchored && !path.starts_with(&self.root) {
+            return VfsPath::from_path(Path::new("..")).unwrap();
         }
-//        if path.as_ref().starts_with(".") {
-//            normalized.push(&self.root);
-//            if path.as_ref().components().count() > 1 {
-//                normalized.push(path.as_ref().strip_prefix(".").unwrap())
-//            }
-//        };
-//        if path.as_ref().starts_with("..") {
-//            if path.as_ref().components().count() == 1 {
-//                if self.root.components().count() == 1 {
-//                    PathBuf::from(&self.root)
-//                } else {
-//                    PathBuf::from(&self.root.parent().unwrap())
-//                }
-//            } else {
-//                if &self.root.components().count() == 1 {
-//                    PathBuf::from(&self.root)
-//                } else {
-//                    PathBuf::from(&self.root.parent().unwrap())
-//                }
-//            }
-//        };
-        normalized
+
+        let relative_part = if is_anchored {
+            path.strip_prefix(&self.root).unwrap()
+        } else {
+            path
+        };
+
+        let cleaned: PathBuf = relative_part.components().collect();
+        VfsPath::from_path(&cleaned)
+            .unwrap_or_else(|| VfsPath::from_path(Path::new("..")).unwrap())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::path::{Path, PathBuf};
+    use std::fs;
+    use std::path::PathBuf;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// An isolated, throwaway fixture tree for one test: `base/root/more/example.txt`,
+    /// with `base` removed again on drop. `root` is the VFS jail; `base` stands in
+    /// for "outside the jail" so escape attempts land somewhere private and
+    /// disposable instead of the shared system temp dir or the repo's own tree.
+    struct Fixture {
+        base: PathBuf,
+        root: PathBuf,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            static COUNTER: AtomicUsize = AtomicUsize::new(0);
+            let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+            let base = std::env::temp_dir().join(format!("toolbox_vfs_{}_{}", std::process::id(), id));
+            let root = base.join("root");
+            fs::create_dir_all(root.join("more")).unwrap();
+            fs::write(root.join("more/example.txt"), b"example").unwrap();
+            Fixture { base, root }
+        }
 
-    const ROOT: &str = "tests/root";
+        fn root(&self) -> PathBuf {
+            self.root.canonicalize().unwrap()
+        }
 
-    fn new_vfs() -> super::VirtualFileSystem {
-        super::VirtualFileSystem::try_new(ROOT).unwrap()
+        fn vfs(&self) -> super::VirtualFileSystem {
+            super::VirtualFileSystem::try_new(&self.root).unwrap()
+        }
     }
 
-    fn cur_dir() -> PathBuf {
-        Path::new(ROOT).canonicalize().unwrap()
+    impl Drop for Fixture {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.base);
+        }
     }
 
     #[test]
     fn root_ok() {
-        let vfs = new_vfs();
-        assert_eq!(vfs.root, cur_dir());
-    }
-
-//    #[test]
-//    fn chroot_ok() {
-//        let mut vfs = new_vfs();
-//
-//        // new root == old root
-//        vfs.chroot(".").unwrap();
-//        assert_eq!(vfs.root, cur_dir());
-//
-//        // new root relative && exists
-//        vfs.chroot("more").unwrap();
-//        assert_eq!(vfs.root, cur_dir().join("more"));
-//
-//        // new root absolute && exists
-//        vfs.chroot("../..").unwrap();
-//        assert_eq!(vfs.root, cur_dir().parent().unwrap());
-//    }
-
-//    #[test]
-//    #[should_panic(expected = "canonicalize error")]
-//    fn chroot_err() {
-//        let mut vfs = new_vfs();
-//
-//        // new root not exists
-//        vfs.chroot("more/not_exists").expect("canonicalize error");
-//    }
-
-//    #[test]
-//    fn absolute_ok() {
-//        let vfs = new_vfs();
-//        assert_eq!(vfs.absolute("more").unwrap(), cur_dir().join("more"));
-//    }
-
-//    #[test]
-//    fn relative_ok() {
-//        let vfs = new_vfs();
-//
-//        assert_eq!(
-//            vfs.relative(cur_dir().join("more")).unwrap(),
-//            Path::new("more")
-//        );
-//    }
-
-//    #[test]
-//    #[should_panic(expected = "Argument is not absolute path.")]
-//    fn relative_err() {
-//        let vfs = new_vfs();
-//
-//        vfs.relative("more")
-//            .expect("Argument is not absolute path.");
-//    }
-
-//    #[test]
-//    fn exists_ok() {
-//        let vfs = new_vfs();
-//        assert!(vfs.exists("more/example.txt"));
-//        assert!(!vfs.exists("foo"));
-//    }
-
-//    #[test]
-//    fn create_dir_ok() {
-//        let vfs = new_vfs();
-//        vfs.create_dir("new_dir").unwrap();
-//    }
-
-//    #[test]
-//    #[should_panic(expected = "too many dirs")]
-//    fn create_dir_err() {
-//        let vfs = new_vfs();
-//        vfs.create_dir("new1/new2").expect("too many dirs");
-//    }
-
-//    #[test]
-//    fn create_dir_all_ok() {
-//        let vfs = new_vfs();
-//        vfs.create_dir_all("new1/new2").unwrap();
-//    }
-
-    //    #[test]
-    //    fn remove_dir_all_ok() {
-    //        let vfs = new_vfs();
-    //        vfs.remove_dir_all("new_dir").unwrap();
-    //    }
+        let fx = Fixture::new();
+        let vfs = fx.vfs();
+        assert_eq!(vfs.root, fx.root());
+    }
+
+    #[test]
+    fn chroot_ok() {
+        let fx = Fixture::new();
+        let mut vfs = fx.vfs();
+
+        // new root == old root
+        vfs.chroot(".").unwrap();
+        assert_eq!(vfs.root, fx.root());
+
+        // new root relative && exists
+        vfs.chroot("more").unwrap();
+        assert_eq!(vfs.root, fx.root().join("more"));
+    }
+
+    #[test]
+    #[should_panic(expected = "canonicalize error")]
+    fn chroot_err() {
+        let fx = Fixture::new();
+        let mut vfs = fx.vfs();
+
+        // new root not exists
+        vfs.chroot("more/not_exists").expect("canonicalize error");
+    }
+
+    #[test]
+    fn chroot_escape_err() {
+        let fx = Fixture::new();
+        let mut vfs = fx.vfs();
+        vfs.chroot("more").unwrap();
+
+        // new root outside of the current root is rejected
+        assert!(vfs.chroot("../..").is_err());
+        assert_eq!(vfs.root, fx.root().join("more"));
+    }
+
+    #[test]
+    fn absolute_ok() {
+        let fx = Fixture::new();
+        let vfs = fx.vfs();
+        assert_eq!(vfs.absolute("more"), fx.root().join("more"));
+    }
+
+    #[test]
+    fn absolute_escape_is_jailed() {
+        let fx = Fixture::new();
+        let vfs = fx.vfs();
+        assert_eq!(vfs.absolute("../../etc"), fx.root().join("etc"));
+    }
+
+    #[test]
+    fn relative_ok() {
+        let fx = Fixture::new();
+        let vfs = fx.vfs();
+
+        assert_eq!(vfs.relative(fx.root().join("more")).to_string(), "/more");
+        assert_eq!(vfs.relative(&fx.root()).to_string(), "/");
+    }
+
+    #[test]
+    fn contains_ok() {
+        let fx = Fixture::new();
+        let vfs = fx.vfs();
+        assert!(vfs.contains("more"));
+        assert!(vfs.contains(fx.root().join("more")));
+    }
+
+    #[test]
+    fn contains_escape_err() {
+        let fx = Fixture::new();
+        let vfs = fx.vfs();
+        // A `..` hop that can't be resolved within `root` escapes it.
+        assert!(!vfs.contains("../outside"));
+        // A foreign absolute path escapes `root` too.
+        assert!(!vfs.contains("/etc/passwd"));
+    }
+
+    #[test]
+    fn exists_ok() {
+        let fx = Fixture::new();
+        let vfs = fx.vfs();
+        assert!(vfs.exists("more/example.txt"));
+        assert!(!vfs.exists("foo"));
+    }
+
+    #[test]
+    fn create_dir_ok() {
+        let fx = Fixture::new();
+        let vfs = fx.vfs();
+        vfs.create_dir("new_dir").unwrap();
+        assert!(vfs.exists("new_dir"));
+    }
+
+    #[test]
+    #[should_panic(expected = "too many dirs")]
+    fn create_dir_err() {
+        let fx = Fixture::new();
+        let vfs = fx.vfs();
+        vfs.create_dir("new1/new2").expect("too many dirs");
+    }
+
+    #[test]
+    fn create_dir_all_ok() {
+        let fx = Fixture::new();
+        let vfs = fx.vfs();
+        vfs.create_dir_all("new1/new2").unwrap();
+    }
+
+    #[test]
+    fn remove_dir_all_ok() {
+        let fx = Fixture::new();
+        let vfs = fx.vfs();
+        vfs.create_dir_all("new_dir").unwrap();
+        vfs.remove_dir_all("new_dir").unwrap();
+        assert!(!vfs.exists("new_dir"));
+    }
+
+    #[test]
+    fn write_read_remove_file_ok() {
+        let fx = Fixture::new();
+        let vfs = fx.vfs();
+        vfs.write("scratch.txt", b"hello").unwrap();
+        assert_eq!(vfs.read("scratch.txt").unwrap(), b"hello");
+        vfs.remove_file("scratch.txt").unwrap();
+        assert!(!vfs.exists("scratch.txt"));
+    }
+
+    #[test]
+    fn read_dir_ok() {
+        let fx = Fixture::new();
+        let vfs = fx.vfs();
+        let entries = vfs.read_dir("more").unwrap();
+        assert!(entries.iter().any(|p| p.to_string() == "/more/example.txt"));
+    }
+
+    #[test]
+    fn write_escape_err() {
+        let fx = Fixture::new();
+        let vfs = fx.vfs();
+        assert!(vfs.write("../escape.txt", b"hello").is_err());
+        assert!(!fx.base.join("escape.txt").exists());
+    }
+
+    #[test]
+    fn create_dir_escape_err() {
+        let fx = Fixture::new();
+        let vfs = fx.vfs();
+        assert!(vfs.create_dir("../escaped_dir").is_err());
+        assert!(!fx.base.join("escaped_dir").exists());
+    }
 }
@@ -0,0 +1,222 @@
+use std::fmt;
+use std::path::Path;
+
+/// A normalized virtual path, stored as a single `String` in canonical
+/// `/a/b/c` form (the empty string being the root), independent of the
+/// host OS path separator.
+///
+/// Unresolved leading `..` hops are tracked separately as `supers`, so a
+/// relative input like `../../a/b` can be resolved against an arbitrary
+/// anchor later instead of being clamped immediately.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VfsPath {
+    segments: String,
+    supers: usize,
+}
+
+impl VfsPath {
+    /// The root path (`/`).
+    ///
+    pub fn root() -> VfsPath {
+        VfsPath {
+            segments: String::new(),
+            supers: 0,
+        }
+    }
+
+    /// Number of unresolved leading `..` hops.
+    ///
+    pub fn supers(&self) -> usize {
+        self.supers
+    }
+
+    /// Parses a host `Path` into a `VfsPath`.
+    ///
+    /// Rejects paths whose string form contains `//` or ends with a
+    /// trailing `/` (other than the bare root). `.` components are
+    /// dropped; leading `..` components are counted as `supers` instead
+    /// of being resolved, since there's nothing yet to resolve them
+    /// against.
+    pub fn from_path(path: &Path) -> Option<VfsPath> {
+        let text = path.to_str()?;
+
+        if text.contains("//") || (text.len() > 1 && text.ends_with('/')) {
+            return None;
+        }
+
+        let mut supers = 0usize;
+        let mut stack: Vec<&str> = Vec::new();
+
+        for segment in text.split('/').filter(|s| !s.is_empty()) {
+            match segment {
+                "." => {}
+                ".." => {
+                    if stack.pop().is_none() {
+                        supers += 1;
+                    }
+                }
+                _ => stack.push(segment),
+            }
+        }
+
+        let mut segments = String::new();
+        for segment in &stack {
+            segments.push('/');
+            segments.push_str(segment);
+        }
+
+        Some(VfsPath { segments, supers })
+    }
+
+    /// Drops any unresolved leading `..` hops, clamping the path to its current segments.
+    ///
+    /// Used when resolving against a jail: hops that would escape the
+    /// anchor are simply discarded rather than carried further.
+    pub fn clamped(mut self) -> VfsPath {
+        self.supers = 0;
+        self
+    }
+
+    /// Appends `relative` onto `self`.
+    ///
+    /// Each of `relative`'s leading `supers` hops pops a segment off
+    /// `self` if one is available, or else carries over onto `self`'s own
+    /// `supers` count; `relative`'s remaining segments are then appended.
+    pub fn push(&mut self, relative: &VfsPath) {
+        for _ in 0..relative.supers {
+            if self.pop().is_none() {
+                self.supers += 1;
+            }
+        }
+        self.segments.push_str(&relative.segments);
+    }
+
+    /// Appends a single path segment.
+    ///
+    /// Returns `None` without modifying `self` if `segment` contains `/`
+    /// or is empty.
+    pub fn push_segment(&mut self, segment: &str) -> Option<()> {
+        if segment.is_empty() || segment.contains('/') {
+            return None;
+        }
+        self.segments.push('/');
+        self.segments.push_str(segment);
+        Some(())
+    }
+
+    /// Removes the last path segment.
+    ///
+    /// Returns `None` without modifying `self` if already at the root.
+    pub fn pop(&mut self) -> Option<()> {
+        let idx = self.segments.rfind('/')?;
+        self.segments.truncate(idx);
+        Some(())
+    }
+
+    /// Returns the path as a host-relative `&str` with no leading separator.
+    ///
+    /// The root is the empty string.
+    pub fn as_relative_str(&self) -> &str {
+        self.segments.trim_start_matches('/')
+    }
+}
+
+impl fmt::Display for VfsPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.segments.is_empty() {
+            write!(f, "/")
+        } else {
+            write!(f, "{}", self.segments)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VfsPath;
+    use std::path::Path;
+
+    #[test]
+    fn root_ok() {
+        let root = VfsPath::root();
+        assert_eq!(root.to_string(), "/");
+        assert_eq!(root.as_relative_str(), "");
+    }
+
+    #[test]
+    fn from_path_ok() {
+        let p = VfsPath::from_path(Path::new("a/b/c")).unwrap();
+        assert_eq!(p.to_string(), "/a/b/c");
+        assert_eq!(p.supers(), 0);
+    }
+
+    #[test]
+    fn from_path_supers_ok() {
+        let p = VfsPath::from_path(Path::new("../../a/b")).unwrap();
+        assert_eq!(p.supers(), 2);
+        assert_eq!(p.to_string(), "/a/b");
+    }
+
+    #[test]
+    fn from_path_interior_parent_dir_ok() {
+        let p = VfsPath::from_path(Path::new("a/../b")).unwrap();
+        assert_eq!(p.supers(), 0);
+        assert_eq!(p.to_string(), "/b");
+    }
+
+    #[test]
+    fn clamped_ok() {
+        let p = VfsPath::from_path(Path::new("../../a")).unwrap();
+        assert_eq!(p.supers(), 2);
+        let clamped = p.clamped();
+        assert_eq!(clamped.supers(), 0);
+        assert_eq!(clamped.to_string(), "/a");
+    }
+
+    #[test]
+    fn from_path_rejects_double_slash() {
+        assert!(VfsPath::from_path(Path::new("a//b")).is_none());
+    }
+
+    #[test]
+    fn from_path_rejects_trailing_slash() {
+        assert!(VfsPath::from_path(Path::new("a/b/")).is_none());
+    }
+
+    #[test]
+    fn push_segment_ok() {
+        let mut p = VfsPath::root();
+        assert_eq!(p.push_segment("a"), Some(()));
+        assert_eq!(p.push_segment("b"), Some(()));
+        assert_eq!(p.to_string(), "/a/b");
+        assert_eq!(p.push_segment("c/d"), None);
+        assert_eq!(p.to_string(), "/a/b");
+    }
+
+    #[test]
+    fn pop_ok() {
+        let mut p = VfsPath::from_path(Path::new("a/b")).unwrap();
+        assert_eq!(p.pop(), Some(()));
+        assert_eq!(p.to_string(), "/a");
+        assert_eq!(p.pop(), Some(()));
+        assert_eq!(p.to_string(), "/");
+        assert_eq!(p.pop(), None);
+    }
+
+    #[test]
+    fn push_ok() {
+        let mut base = VfsPath::from_path(Path::new("a/b")).unwrap();
+        let relative = VfsPath::from_path(Path::new("../c")).unwrap();
+        base.push(&relative);
+        assert_eq!(base.to_string(), "/a/c");
+    }
+
+    #[test]
+    fn push_carries_supers_past_root() {
+        let mut base = VfsPath::from_path(Path::new("a")).unwrap();
+        let relative = VfsPath::from_path(Path::new("../../b")).unwrap();
+        base.push(&relative);
+        assert_eq!(base.supers(), 1);
+        assert_eq!(base.to_string(), "/b");
+    }
+}